@@ -0,0 +1,119 @@
+//! Lets an [Executor] be `.await`ed inside an async runtime instead of
+//! busy-looping on a dedicated thread. Enabled by the `async` feature.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc::{self, Sender};
+use std::sync::OnceLock;
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+use crate::{Executor, InterruptReason, Interruptable, Status, TimeoutError};
+
+/// How many [Interruptable::poll] steps a single [Future::poll] call
+/// performs before yielding the thread back to the runtime.
+const STEPS_PER_POLL: usize = 64;
+
+/// How long to wait before asking the runtime to poll this [Executor]
+/// again, mirroring the short re-arm interval of e.g. tokio's `Timeout`.
+const YIELD_INTERVAL: Duration = Duration::from_millis(1);
+
+/// A pending wake-up, ordered by `at` so [BinaryHeap] can be used as a
+/// min-heap of the soonest deadline.
+struct Wake {
+    at: Instant,
+    waker: Waker,
+}
+
+impl PartialEq for Wake {
+    fn eq(&self, other: &Self) -> bool {
+        self.at == other.at
+    }
+}
+impl Eq for Wake {}
+impl PartialOrd for Wake {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Wake {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so the earliest deadline sorts first in a `BinaryHeap`.
+        other.at.cmp(&self.at)
+    }
+}
+
+/// A single background thread shared by every `Executor::poll` call,
+/// rather than spawning a new OS thread per yield. It parks until the
+/// nearest scheduled wake-up, or until a new one is registered.
+fn timer_sender() -> &'static Sender<Wake> {
+    static SENDER: OnceLock<Sender<Wake>> = OnceLock::new();
+    SENDER.get_or_init(|| {
+        let (tx, rx) = mpsc::channel::<Wake>();
+        std::thread::spawn(move || {
+            let mut pending = BinaryHeap::new();
+            loop {
+                let next = match pending.peek() {
+                    Some(Wake { at, .. }) => rx.recv_timeout(at.saturating_duration_since(Instant::now())),
+                    None => rx.recv().map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+                };
+                match next {
+                    Ok(wake) => pending.push(wake),
+                    Err(mpsc::RecvTimeoutError::Timeout) => {}
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                }
+                let now = Instant::now();
+                while matches!(pending.peek(), Some(Wake { at, .. }) if *at <= now) {
+                    pending.pop().unwrap().waker.wake();
+                }
+            }
+        });
+        tx
+    })
+}
+
+impl<I, T> Future for Executor<I, T>
+where
+    I: Interruptable<Output = T> + Unpin,
+{
+    type Output = Result<T, TimeoutError<T>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let start = *this.start.get_or_insert_with(Instant::now);
+
+        for _ in 0..STEPS_PER_POLL {
+            if let Some(interrupt) = &this.interrupt {
+                if interrupt.is_tripped() {
+                    return Poll::Ready(Err(TimeoutError {
+                        reason: InterruptReason::Cancelled,
+                        partial: this.func.partial_result(),
+                    }));
+                }
+            }
+
+            match this.func.poll() {
+                Status::Done(t) => return Poll::Ready(Ok(t)),
+                Status::Pending => {}
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= this.deadline {
+                return Poll::Ready(Err(TimeoutError {
+                    reason: InterruptReason::DeadlineExceeded {
+                        late_by: elapsed - this.deadline,
+                    },
+                    partial: this.func.partial_result(),
+                }));
+            }
+        }
+
+        let _ = timer_sender().send(Wake {
+            at: Instant::now() + YIELD_INTERVAL,
+            waker: cx.waker().clone(),
+        });
+        Poll::Pending
+    }
+}
@@ -0,0 +1,136 @@
+//! Lets opaque work that has no explicit cursor (like [crate::Interruptable]
+//! implementors keep, e.g. `Sort::idx`) still be interrupted, by running it
+//! on its own stack and suspending it at checkpoints the closure chooses.
+//!
+//! The closure's stack and locals are a real OS thread's stack and locals,
+//! so they stay alive between yields and a resumed [Coroutine] continues
+//! exactly where it left off rather than restarting.
+
+use std::cell::Cell;
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+use std::thread::JoinHandle;
+
+use crate::{Interruptable, Status};
+
+enum Message<T> {
+    Yielded,
+    Done(T),
+}
+
+/// Handed to the closure running inside a [Coroutine] so it can suspend
+/// itself at a checkpoint and hand control back to whatever is driving
+/// the [Coroutine], e.g. an [crate::Executor].
+pub struct Yielder<T> {
+    to_driver: SyncSender<Message<T>>,
+    resume: Receiver<()>,
+    /// Set once the driving [Coroutine] has been dropped and is no longer
+    /// listening. Once set, further checkpoints return immediately instead
+    /// of sending into the now-unread `to_driver` channel, which would
+    /// otherwise block forever once its buffer fills up.
+    abandoned: Cell<bool>,
+}
+
+impl<T> Yielder<T> {
+    /// Suspend the coroutine until it is resumed by the next
+    /// [Interruptable::poll] call.
+    pub fn checkpoint(&self) {
+        if self.abandoned.get() {
+            return;
+        }
+        let _ = self.to_driver.send(Message::Yielded);
+        if self.resume.recv().is_err() {
+            self.abandoned.set(true);
+        }
+    }
+}
+
+/// Wraps a closure on its own thread so it can be preempted at
+/// [Yielder::checkpoint] calls, regardless of how the closure is written
+/// internally.
+pub struct Coroutine<T> {
+    resume: Option<SyncSender<()>>,
+    yielded: Receiver<Message<T>>,
+    handle: Option<JoinHandle<()>>,
+    done: bool,
+}
+
+impl<T: Send + 'static> Coroutine<T> {
+    /// Create a coroutine that will run `f` on its own stack once driven,
+    /// passing it a [Yielder] it can use to suspend at checkpoints.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(&Yielder<T>) -> T + Send + 'static,
+    {
+        let (resume_tx, resume_rx) = sync_channel(0);
+        let (done_tx, done_rx) = sync_channel(0);
+
+        let handle = std::thread::spawn(move || {
+            // Wait to be driven for the first time before doing any work.
+            if resume_rx.recv().is_err() {
+                return;
+            }
+            let yielder = Yielder {
+                to_driver: done_tx.clone(),
+                resume: resume_rx,
+                abandoned: Cell::new(false),
+            };
+            let result = f(&yielder);
+            // If the driver abandoned us (the `Coroutine` was dropped
+            // mid-flight), nobody is reading `done_tx` anymore, so sending
+            // here would block forever and deadlock `Coroutine::drop`'s
+            // `handle.join()`. Skip the send in that case; the result is
+            // simply discarded.
+            if !yielder.abandoned.get() {
+                let _ = done_tx.send(Message::Done(result));
+            }
+        });
+
+        Self {
+            resume: Some(resume_tx),
+            yielded: done_rx,
+            handle: Some(handle),
+            done: false,
+        }
+    }
+}
+
+impl<T: Send + 'static> Interruptable for Coroutine<T> {
+    type Output = T;
+
+    fn poll(&mut self) -> Status<Self::Output> {
+        let Some(resume) = &self.resume else {
+            return Status::Pending;
+        };
+        if resume.send(()).is_err() {
+            return Status::Pending;
+        }
+
+        match self.yielded.recv() {
+            Ok(Message::Yielded) => Status::Pending,
+            Ok(Message::Done(t)) => {
+                self.done = true;
+                self.resume = None;
+                Status::Done(t)
+            }
+            Err(_) => Status::Pending,
+        }
+    }
+
+    /// The coroutine's state lives on its own thread's stack, so there is
+    /// nothing to reconstruct here; this always returns `None`.
+    fn partial_result(&self) -> Option<Self::Output> {
+        None
+    }
+}
+
+impl<T> Drop for Coroutine<T> {
+    fn drop(&mut self) {
+        // Dropping the sender unblocks a coroutine parked in
+        // `Yielder::checkpoint`, letting it run to completion (or notice
+        // the channel is gone) instead of leaking a blocked thread.
+        self.resume.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
@@ -6,27 +6,93 @@
 
 /// The default items to import
 pub mod prelude {
-    pub use crate::{exec_interruptable, Executor, Interruptable, Status};
+    pub use crate::{
+        exec_interruptable, Executor, Interrupt, InterruptReason, Interruptable, RunOutcome,
+        Status,
+    };
 }
 
+#[cfg(feature = "async")]
+pub mod async_executor;
+pub mod coroutine;
+pub mod scheduler;
+pub mod stream;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-/// Error if the deadline of an [Interruptable] is missed.
-/// Contains the amount of time by which the deadline was missed
+/// Why an [Executor] stopped before its [Interruptable] finished.
+#[derive(Debug)]
+pub enum InterruptReason {
+    /// The deadline passed before the [Interruptable] finished.
+    DeadlineExceeded {
+        /// The amount of time by which the deadline was missed.
+        late_by: Duration,
+    },
+    /// The [Executor] was cancelled through an [Interrupt].
+    Cancelled,
+}
+
+/// Error returned when an [Executor] is interrupted before its
+/// [Interruptable] finished. Contains the reason for the interruption
 /// as well as a partial result if one exists.
 #[derive(Debug)]
-pub struct TimeoutError<P>(Duration, Option<P>);
+pub struct TimeoutError<P> {
+    reason: InterruptReason,
+    partial: Option<P>,
+}
 
 impl<P> TimeoutError<P> {
-    /// Returns the amount by which the deadline was missed.
-    pub fn late_by(&self) -> Duration {
-        self.0
+    /// Returns the reason the [Executor] was interrupted.
+    pub fn reason(&self) -> &InterruptReason {
+        &self.reason
+    }
+
+    /// Returns the amount by which the deadline was missed, if the
+    /// [Executor] was interrupted by [InterruptReason::DeadlineExceeded]
+    /// rather than cancelled.
+    pub fn late_by(&self) -> Option<Duration> {
+        match self.reason {
+            InterruptReason::DeadlineExceeded { late_by } => Some(late_by),
+            InterruptReason::Cancelled => None,
+        }
     }
 
     /// Returns the partial result of the function that caused
     /// the error.
     pub fn partial_result(&self) -> Option<&P> {
-        self.1.as_ref()
+        self.partial.as_ref()
+    }
+}
+
+/// A handle that can be cloned and shared across threads to cooperatively
+/// cancel a running [Executor] from the outside. An [Executor] checks the
+/// handle between each [Interruptable::poll] alongside its deadline.
+#[derive(Debug, Clone)]
+pub struct Interrupt(Arc<AtomicBool>);
+
+impl Interrupt {
+    /// Create a new, untripped [Interrupt].
+    pub fn new() -> Self {
+        Self(Arc::new(AtomicBool::new(false)))
+    }
+
+    /// Trip the interrupt, causing any [Executor] watching it to stop at
+    /// its next check.
+    pub fn trip(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns whether the interrupt has been tripped.
+    pub fn is_tripped(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for Interrupt {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -49,8 +115,14 @@ pub struct Executor<I, T>
 where
     I: Interruptable<Output = T>,
 {
-    func: I,
-    deadline: Duration,
+    pub(crate) func: I,
+    pub(crate) deadline: Duration,
+    pub(crate) interrupt: Option<Interrupt>,
+    /// When this [Executor] is driven as a [std::future::Future], this is
+    /// the instant the first poll happened, used to track the deadline
+    /// across repeated `Future::poll` calls.
+    #[cfg(feature = "async")]
+    pub(crate) start: Option<Instant>,
 }
 
 impl<I, T> Executor<I, T>
@@ -58,21 +130,49 @@ where
     I: Interruptable<Output = T>,
 {
     pub fn new(func: I, deadline: Duration) -> Self {
-        Self { func, deadline }
+        Self {
+            func,
+            deadline,
+            interrupt: None,
+            #[cfg(feature = "async")]
+            start: None,
+        }
+    }
+
+    /// Create an [Executor] that also stops early when `interrupt` is
+    /// tripped from another thread, independent of the deadline.
+    pub fn with_interrupt(func: I, deadline: Duration, interrupt: Interrupt) -> Self {
+        Self {
+            func,
+            deadline,
+            interrupt: Some(interrupt),
+            #[cfg(feature = "async")]
+            start: None,
+        }
     }
 
     pub fn run(&mut self) -> Result<T, TimeoutError<T>> {
         let start = Instant::now();
         loop {
+            if let Some(interrupt) = &self.interrupt {
+                if interrupt.is_tripped() {
+                    return Err(TimeoutError {
+                        reason: InterruptReason::Cancelled,
+                        partial: self.func.partial_result(),
+                    });
+                }
+            }
             match self.func.poll() {
                 Status::Done(t) => return Ok(t),
                 Status::Pending => {
                     let current_time = start.elapsed();
                     if current_time >= self.deadline {
-                        return Err(TimeoutError(
-                            current_time - self.deadline,
-                            self.func.partial_result(),
-                        ));
+                        return Err(TimeoutError {
+                            reason: InterruptReason::DeadlineExceeded {
+                                late_by: current_time - self.deadline,
+                            },
+                            partial: self.func.partial_result(),
+                        });
                     }
                 }
             }
@@ -82,6 +182,41 @@ where
     pub fn partial_result(&self) -> Option<T> {
         self.func.partial_result()
     }
+
+    /// Drive the wrapped [Interruptable] for at most `budget`, then return
+    /// control to the caller instead of discarding progress.
+    ///
+    /// Unlike [Executor::run], a [RunOutcome::Suspended] result leaves the
+    /// [Interruptable] untouched: call `run_for` again to keep driving it
+    /// from exactly where it left off, e.g. once per frame or tick.
+    ///
+    /// Only `budget` bounds this call: the [Executor]'s deadline and its
+    /// [Interrupt], if any, are not checked.
+    pub fn run_for(&mut self, budget: Duration) -> RunOutcome<T> {
+        let start = Instant::now();
+        loop {
+            match self.func.poll() {
+                Status::Done(t) => return RunOutcome::Done(t),
+                Status::Pending => {
+                    if start.elapsed() >= budget {
+                        return RunOutcome::Suspended;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The outcome of driving an [Executor] for a single time slice via
+/// [Executor::run_for].
+#[derive(Debug)]
+pub enum RunOutcome<T> {
+    /// The [Interruptable] finished within the given budget.
+    Done(T),
+    /// The budget was exhausted before the [Interruptable] finished.
+    /// It has not been discarded and can be resumed with another
+    /// call to [Executor::run_for].
+    Suspended,
 }
 
 /// The status of an [Interruptable]. Should be [Status::Pending] while
@@ -97,4 +232,7 @@ macro_rules! exec_interruptable {
     ($func:ident, $duration:expr) => {
         Executor::new($func, $duration).run()
     };
+    ($func:ident, $duration:expr, $interrupt:expr) => {
+        Executor::with_interrupt($func, $duration, $interrupt).run()
+    };
 }
@@ -0,0 +1,187 @@
+//! A [Scheduler] drives many [Interruptable]s with different [Interruptable::Output]
+//! types fairly within a shared deadline, instead of running one [crate::Executor]
+//! to completion before starting the next.
+
+use std::any::Any;
+use std::time::{Duration, Instant};
+
+use crate::{Interruptable, Status};
+
+/// The state of a single task managed by a [Scheduler].
+pub enum TaskState {
+    /// The task has recently been using more than a tenth and at most half
+    /// of its slice.
+    Busy,
+    /// The task has recently used at most a tenth of its slice (e.g. it has
+    /// not been polled yet), or has already delivered its output and is no
+    /// longer scheduled.
+    Idle,
+    /// The task finished. Returned once, the first time its completion is
+    /// observed through [Scheduler::task_state].
+    Done(Box<dyn Any>),
+    /// The task has been consuming most of its slice in recent rounds and
+    /// is being visited less often. The fraction is how much of its slice
+    /// it has recently been using, in `0.0..=1.0`.
+    Throttled(f32),
+}
+
+/// A single task owned by a [Scheduler]. Its [Interruptable::Output] is
+/// erased to [Box<dyn Any>] so tasks with different output types can be
+/// stored side by side.
+struct Task {
+    func: Box<dyn FnMut() -> Status<Box<dyn Any>>>,
+    recent_usage: f32,
+    /// Set once the task has returned [Status::Done]. Kept forever so a
+    /// later [Scheduler::run] call never polls it again.
+    completed: bool,
+    /// The task's output, taken the first time it is observed through
+    /// [Scheduler::task_state].
+    output: Option<Box<dyn Any>>,
+}
+
+/// The result of a [Scheduler::run] call: the ids of tasks that finished
+/// during that call, and the ids of tasks still running.
+pub type RunResult = (Vec<usize>, Vec<usize>);
+
+/// Round-robin scheduler for many heterogeneous [Interruptable]s.
+///
+/// Each round, every still-running task is polled repeatedly for up to
+/// `slice` (or until it finishes), then the scheduler moves on to the next
+/// task. Tasks that consume most of their slice are reported as
+/// [TaskState::Throttled] and are skipped some rounds, so a single
+/// CPU-hungry `poll()` cannot starve the others.
+pub struct Scheduler {
+    tasks: Vec<Task>,
+    slice: Duration,
+    round: u64,
+}
+
+impl Scheduler {
+    /// Create a scheduler that gives each task at most `slice` per round.
+    pub fn new(slice: Duration) -> Self {
+        Self {
+            tasks: Vec::new(),
+            slice,
+            round: 0,
+        }
+    }
+
+    /// Register an [Interruptable] with the scheduler, returning the task
+    /// id it can be looked up by in the results of [Scheduler::run] and
+    /// [Scheduler::task_state].
+    pub fn register<I>(&mut self, mut task: I) -> usize
+    where
+        I: Interruptable + 'static,
+        I::Output: 'static,
+    {
+        self.tasks.push(Task {
+            func: Box::new(move || match task.poll() {
+                Status::Done(t) => Status::Done(Box::new(t) as Box<dyn Any>),
+                Status::Pending => Status::Pending,
+            }),
+            recent_usage: 0.0,
+            completed: false,
+            output: None,
+        });
+        self.tasks.len() - 1
+    }
+
+    /// Round-robin the not-yet-completed tasks until `deadline` elapses or
+    /// every task is done, whichever comes first.
+    ///
+    /// Tasks that completed in a previous call to `run` are never polled
+    /// again. Returns the ids of tasks that finished during this call (fetch
+    /// their output with [Scheduler::task_state]) and the ids of tasks
+    /// still running.
+    pub fn run(&mut self, deadline: Duration) -> RunResult {
+        let start = Instant::now();
+        let mut newly_done = Vec::new();
+        let mut alive: Vec<usize> = (0..self.tasks.len())
+            .filter(|&id| !self.tasks[id].completed)
+            .collect();
+
+        while !alive.is_empty() && start.elapsed() < deadline {
+            self.round = self.round.wrapping_add(1);
+            let round = self.round;
+            let mut still_alive = Vec::with_capacity(alive.len());
+
+            for id in alive {
+                let task = &mut self.tasks[id];
+
+                // A task that has recently been hogging its slice is
+                // skipped this round with a probability equal to how much
+                // of its slice it has been using.
+                if task.recent_usage > 0.5 && round_robin_skip(round, id, task.recent_usage) {
+                    still_alive.push(id);
+                    continue;
+                }
+
+                let slice_start = Instant::now();
+                let mut output = None;
+                loop {
+                    match (task.func)() {
+                        Status::Done(t) => {
+                            output = Some(t);
+                            break;
+                        }
+                        Status::Pending => {
+                            if slice_start.elapsed() >= self.slice {
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                let used =
+                    slice_start.elapsed().as_secs_f32() / self.slice.as_secs_f32().max(f32::EPSILON);
+                task.recent_usage = task.recent_usage * 0.5 + used.min(1.0) * 0.5;
+
+                match output {
+                    Some(output) => {
+                        task.completed = true;
+                        task.output = Some(output);
+                        newly_done.push(id);
+                    }
+                    None => still_alive.push(id),
+                }
+            }
+            alive = still_alive;
+        }
+
+        (newly_done, alive)
+    }
+
+    /// Current state of a task.
+    ///
+    /// The first call observing a completed task returns its output as
+    /// [TaskState::Done]; later calls for the same task return
+    /// [TaskState::Idle] since there is nothing left to report.
+    pub fn task_state(&mut self, id: usize) -> TaskState {
+        let task = &mut self.tasks[id];
+        if let Some(output) = task.output.take() {
+            return TaskState::Done(output);
+        }
+        if task.completed {
+            return TaskState::Idle;
+        }
+        if task.recent_usage > 0.5 {
+            TaskState::Throttled(task.recent_usage)
+        } else if task.recent_usage > 0.1 {
+            TaskState::Busy
+        } else {
+            TaskState::Idle
+        }
+    }
+}
+
+/// Deterministic pseudo-random skip, seeded from the round counter and
+/// task id so throttled tasks are visited less often without pulling in a
+/// dependency on a `rand` crate, and without depending on wall-clock time.
+fn round_robin_skip(round: u64, id: usize, usage: f32) -> bool {
+    let mut hash = round.wrapping_mul(0x9E3779B97F4A7C15).wrapping_add(id as u64);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+    let sample = (hash >> 40) as f32 / (1u64 << 24) as f32;
+    sample < usage
+}
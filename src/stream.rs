@@ -0,0 +1,99 @@
+//! Streaming counterpart to [crate::Interruptable]: an [InterruptableStream]
+//! yields a sequence of items instead of a single result, and a
+//! [StreamExecutor] bounds how long it is allowed to take to do so.
+
+use std::time::{Duration, Instant};
+
+use crate::Status;
+
+/// An [InterruptableStream] yields items one at a time, like
+/// [crate::Interruptable] but producing a sequence instead of a single
+/// result.
+pub trait InterruptableStream {
+    /// The type of item yielded by the stream.
+    type Item;
+
+    /// Check if the next item is ready. [Status::Done(None)] means the
+    /// stream is exhausted and will not yield any more items.
+    fn poll_next(&mut self) -> Status<Option<Self::Item>>;
+}
+
+/// How a [StreamExecutor] measures time against its deadline.
+pub enum StreamDeadline {
+    /// The deadline bounds the total time to drain all items.
+    Total(Duration),
+    /// The deadline resets after each yielded item, so a slow-but-steady
+    /// producer is not aborted as long as it keeps making progress.
+    PerItem(Duration),
+}
+
+/// Error returned when a [StreamExecutor] is interrupted before its
+/// [InterruptableStream] was exhausted. Contains the items already
+/// produced as well as the amount by which the deadline was missed.
+#[derive(Debug)]
+pub struct StreamTimeoutError<T> {
+    items: Vec<T>,
+    late_by: Duration,
+}
+
+impl<T> StreamTimeoutError<T> {
+    /// Returns the amount by which the deadline was missed.
+    pub fn late_by(&self) -> Duration {
+        self.late_by
+    }
+
+    /// Returns the items produced before the deadline was missed.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Consumes the error, returning the items produced before the
+    /// deadline was missed.
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+/// Drains an [InterruptableStream] under a [StreamDeadline].
+pub struct StreamExecutor<S> {
+    stream: S,
+    deadline: StreamDeadline,
+}
+
+impl<S> StreamExecutor<S>
+where
+    S: InterruptableStream,
+{
+    pub fn new(stream: S, deadline: StreamDeadline) -> Self {
+        Self { stream, deadline }
+    }
+
+    /// Drain the stream until it is exhausted or the deadline is missed.
+    pub fn run(&mut self) -> Result<Vec<S::Item>, StreamTimeoutError<S::Item>> {
+        let mut items = Vec::new();
+        let total_start = Instant::now();
+        let mut item_start = Instant::now();
+
+        loop {
+            match self.stream.poll_next() {
+                Status::Done(None) => return Ok(items),
+                Status::Done(Some(item)) => {
+                    items.push(item);
+                    item_start = Instant::now();
+                }
+                Status::Pending => {}
+            }
+
+            let (elapsed, deadline) = match self.deadline {
+                StreamDeadline::Total(deadline) => (total_start.elapsed(), deadline),
+                StreamDeadline::PerItem(deadline) => (item_start.elapsed(), deadline),
+            };
+            if elapsed >= deadline {
+                return Err(StreamTimeoutError {
+                    items,
+                    late_by: elapsed - deadline,
+                });
+            }
+        }
+    }
+}